@@ -466,6 +466,8 @@ pub(crate) unsafe fn optimize(
         llvm::LLVMWriteBitcodeToFile(llmod, out.as_ptr());
     }
 
+    print_llvm_ir_for(cgcx, llmod, &module.name, "before optimization");
+
     if let Some(opt_level) = config.opt_level {
         if should_use_new_llvm_pass_manager(config) {
             let opt_stage = match cgcx.lto {
@@ -601,6 +603,38 @@ pub(crate) unsafe fn optimize(
     Ok(())
 }
 
+/// Implements `-Z print-llvm-ir-for`: prints the IR of every function in `llmod`
+/// whose demangled name contains the given filter, labelled with `stage`.
+fn print_llvm_ir_for(
+    cgcx: &CodegenContext<LlvmCodegenBackend>,
+    llmod: &llvm::Module,
+    module_name: &str,
+    stage: &str,
+) {
+    let filter = match cgcx.opts.debugging_opts.print_llvm_ir_for {
+        Some(ref filter) => filter,
+        None => return,
+    };
+
+    // Build the whole output first, so that modules being processed on
+    // other threads don't interleave their functions with ours.
+    let mut output = String::new();
+    for llfn in base::iter_functions(llmod) {
+        let name = String::from_utf8_lossy(llvm::get_value_name(llfn));
+        let demangled = format!("{:#}", rustc_demangle::demangle(&name));
+        if demangled.contains(filter.as_str()) {
+            output.push_str(&format!(
+                "; `{}` {} (module `{}`)\n{:?}\n",
+                demangled, stage, module_name, llfn
+            ));
+        }
+    }
+
+    if !output.is_empty() {
+        print!("{}", output);
+    }
+}
+
 unsafe fn add_sanitizer_passes(config: &ModuleConfig, passes: &mut Vec<&'static mut llvm::Pass>) {
     if config.sanitizer.contains(SanitizerSet::ADDRESS) {
         let recover = config.sanitizer_recover.contains(SanitizerSet::ADDRESS);
@@ -632,6 +666,8 @@ pub(crate) unsafe fn codegen(
         let module_name = Some(&module_name[..]);
         let handlers = DiagnosticHandlers::new(cgcx, diag_handler, llcx);
 
+        print_llvm_ir_for(cgcx, llmod, &module.name, "after optimization");
+
         if cgcx.msvc_imps_needed {
             create_msvc_imps(cgcx, llcx, llmod);
         }
@@ -590,31 +590,32 @@ impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
     ) -> Bx::Value {
         let is_float = input_ty.is_floating_point();
         let is_signed = input_ty.is_signed();
+        let fast_math = bx.cx().sess().opts.debugging_opts.fast_math;
         match op {
             mir::BinOp::Add => {
                 if is_float {
-                    bx.fadd(lhs, rhs)
+                    if fast_math { bx.fadd_fast(lhs, rhs) } else { bx.fadd(lhs, rhs) }
                 } else {
                     bx.add(lhs, rhs)
                 }
             }
             mir::BinOp::Sub => {
                 if is_float {
-                    bx.fsub(lhs, rhs)
+                    if fast_math { bx.fsub_fast(lhs, rhs) } else { bx.fsub(lhs, rhs) }
                 } else {
                     bx.sub(lhs, rhs)
                 }
             }
             mir::BinOp::Mul => {
                 if is_float {
-                    bx.fmul(lhs, rhs)
+                    if fast_math { bx.fmul_fast(lhs, rhs) } else { bx.fmul(lhs, rhs) }
                 } else {
                     bx.mul(lhs, rhs)
                 }
             }
             mir::BinOp::Div => {
                 if is_float {
-                    bx.fdiv(lhs, rhs)
+                    if fast_math { bx.fdiv_fast(lhs, rhs) } else { bx.fdiv(lhs, rhs) }
                 } else if is_signed {
                     bx.sdiv(lhs, rhs)
                 } else {
@@ -623,7 +624,7 @@ impl<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>> FunctionCx<'a, 'tcx, Bx> {
             }
             mir::BinOp::Rem => {
                 if is_float {
-                    bx.frem(lhs, rhs)
+                    if fast_math { bx.frem_fast(lhs, rhs) } else { bx.frem(lhs, rhs) }
                 } else if is_signed {
                     bx.srem(lhs, rhs)
                 } else {
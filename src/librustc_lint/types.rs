@@ -1226,6 +1226,10 @@ impl<'tcx> LateLintPass<'tcx> for VariantSizeDifferences {
                                           larger ({} bytes) than the next largest",
                             largest
                         ))
+                        .help(
+                            "consider boxing the large fields to reduce the total size of the \
+                             enum",
+                        )
                         .emit()
                     },
                 );
@@ -1160,6 +1160,15 @@ impl<'a, 'tcx> CrateMetadataRef<'a> {
             .unwrap_or_default()
     }
 
+    fn get_cross_crate_inlinable(&self, id: DefIndex) -> bool {
+        self.root
+            .tables
+            .cross_crate_inlinable
+            .get(self, id)
+            .filter(|_| !self.is_proc_macro(id))
+            .map_or(false, |inlinable| inlinable.decode(self))
+    }
+
     fn get_promoted_mir(&self, tcx: TyCtxt<'tcx>, id: DefIndex) -> IndexVec<Promoted, Body<'tcx>> {
         self.root
             .tables
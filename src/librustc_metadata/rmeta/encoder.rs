@@ -1107,8 +1107,10 @@ impl EncodeContext<'a, 'tcx> {
             hir::ImplItemKind::Const(..) => true,
             hir::ImplItemKind::Fn(ref sig, _) => {
                 let generics = self.tcx.generics_of(def_id);
+                let cross_crate_inlinable = self.encode_cross_crate_inlinable(def_id);
                 let needs_inline = (generics.requires_monomorphization(self.tcx)
-                    || tcx.codegen_fn_attrs(def_id).requests_inline())
+                    || tcx.codegen_fn_attrs(def_id).requests_inline()
+                    || cross_crate_inlinable)
                     && !self.metadata_output_only();
                 let is_const_fn = sig.header.constness == hir::Constness::Const;
                 let always_encode_mir = self.tcx.sess.opts.debugging_opts.always_encode_mir;
@@ -1139,6 +1141,15 @@ impl EncodeContext<'a, 'tcx> {
         }
     }
 
+    fn encode_cross_crate_inlinable(&mut self, def_id: DefId) -> bool {
+        debug!("EncodeContext::encode_cross_crate_inlinable({:?})", def_id);
+        let cross_crate_inlinable = self.tcx.cross_crate_inlinable(def_id);
+        if cross_crate_inlinable {
+            record!(self.tables.cross_crate_inlinable[def_id] <- true);
+        }
+        cross_crate_inlinable
+    }
+
     fn encode_promoted_mir(&mut self, def_id: LocalDefId) {
         debug!("EncodeContext::encode_promoted_mir({:?})", def_id);
         if self.tcx.mir_keys(LOCAL_CRATE).contains(&def_id) {
@@ -1403,8 +1414,10 @@ impl EncodeContext<'a, 'tcx> {
             hir::ItemKind::Static(..) | hir::ItemKind::Const(..) => true,
             hir::ItemKind::Fn(ref sig, ..) => {
                 let generics = tcx.generics_of(def_id);
+                let cross_crate_inlinable = self.encode_cross_crate_inlinable(def_id);
                 let needs_inline = (generics.requires_monomorphization(tcx)
-                    || tcx.codegen_fn_attrs(def_id).requests_inline())
+                    || tcx.codegen_fn_attrs(def_id).requests_inline()
+                    || cross_crate_inlinable)
                     && !self.metadata_output_only();
                 let always_encode_mir = self.tcx.sess.opts.debugging_opts.always_encode_mir;
                 needs_inline || sig.header.constness == hir::Constness::Const || always_encode_mir
@@ -1897,7 +1910,8 @@ impl<'tcx, 'v> ParItemLikeVisitor<'v> for PrefetchVisitor<'tcx> {
                 let def_id = tcx.hir().local_def_id(item.hir_id);
                 let generics = tcx.generics_of(def_id.to_def_id());
                 let needs_inline = generics.requires_monomorphization(tcx)
-                    || tcx.codegen_fn_attrs(def_id.to_def_id()).requests_inline();
+                    || tcx.codegen_fn_attrs(def_id.to_def_id()).requests_inline()
+                    || tcx.cross_crate_inlinable(def_id.to_def_id());
                 if needs_inline || sig.header.constness == hir::Constness::Const {
                     self.prefetch_mir(def_id)
                 }
@@ -1922,7 +1936,8 @@ impl<'tcx, 'v> ParItemLikeVisitor<'v> for PrefetchVisitor<'tcx> {
                 let def_id = tcx.hir().local_def_id(impl_item.hir_id);
                 let generics = tcx.generics_of(def_id.to_def_id());
                 let needs_inline = generics.requires_monomorphization(tcx)
-                    || tcx.codegen_fn_attrs(def_id.to_def_id()).requests_inline();
+                    || tcx.codegen_fn_attrs(def_id.to_def_id()).requests_inline()
+                    || tcx.cross_crate_inlinable(def_id.to_def_id());
                 let is_const_fn = sig.header.constness == hir::Constness::Const;
                 if needs_inline || is_const_fn {
                     self.prefetch_mir(def_id)
@@ -286,6 +286,7 @@ define_tables! {
     mir: Table<DefIndex, Lazy!(mir::Body<'tcx>)>,
     promoted_mir: Table<DefIndex, Lazy!(IndexVec<mir::Promoted, mir::Body<'tcx>>)>,
     unused_generic_params: Table<DefIndex, Lazy<FiniteBitSet<u64>>>,
+    cross_crate_inlinable: Table<DefIndex, Lazy<bool>>,
 }
 
 #[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
@@ -795,6 +795,15 @@ rustc_queries! {
             storage(ArenaCacheSelector<'tcx>)
             cache_on_disk_if { true }
         }
+
+        /// Returns `true` if `-Z cross-crate-inline-threshold` makes `def_id` available for
+        /// inlining in other crates, as if it were marked `#[inline]`.
+        query cross_crate_inlinable(def_id: DefId) -> bool {
+            desc {
+                |tcx| "checking whether `{}` can be inlined across crates",
+                    tcx.def_path_str(def_id)
+            }
+        }
     }
 
     Other {
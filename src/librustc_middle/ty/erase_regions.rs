@@ -14,7 +14,12 @@ fn erase_regions_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Ty<'tcx> {
 impl<'tcx> TyCtxt<'tcx> {
     /// Returns an equivalent value with all free regions removed (note
     /// that late-bound regions remain, because they are important for
-    /// subtyping, but they are anonymized and normalized as well)..
+    /// subtyping, but they are anonymized and normalized as well).
+    ///
+    /// Erasing the regions of a type without inference variables goes
+    /// through the `erase_regions_ty` query, so the result for each such
+    /// type is computed only once. Types with inference variables are
+    /// folded directly every time.
     pub fn erase_regions<T>(self, value: &T) -> T
     where
         T: TypeFoldable<'tcx>,
@@ -233,6 +233,7 @@ impl<'tcx> InstanceDef<'tcx> {
             });
         }
         tcx.codegen_fn_attrs(self.def_id()).requests_inline()
+            || tcx.cross_crate_inlinable(self.def_id())
     }
 
     pub fn requires_caller_location(&self, tcx: TyCtxt<'_>) -> bool {
@@ -332,6 +333,8 @@ impl<'tcx> Instance<'tcx> {
         def: ty::WithOptConstParam<DefId>,
         substs: SubstsRef<'tcx>,
     ) -> Result<Option<Instance<'tcx>>, ErrorReported> {
+        substs.debug_assert_matches(tcx, def.did);
+
         // All regions in the result of this query are erased, so it's
         // fine to erase all of the input regions.
 
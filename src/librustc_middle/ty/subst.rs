@@ -181,6 +181,14 @@ impl<'tcx> Decodable for GenericArg<'tcx> {
 }
 
 /// A substitution mapping generic parameters to new values.
+///
+/// The substitutions for an item are laid out as a single flat list: the
+/// parameters of the parent item (e.g., the trait or impl containing a
+/// method, including `Self` for traits) come first, followed by the item's
+/// own parameters. Each `GenericParamDef::index` is an index into this list,
+/// so callers never need to compute offsets into distinct parameter spaces;
+/// use `for_item`, `extend_to`, `rebase_onto` and `truncate_to` to move
+/// between the substitutions of a parent and its children.
 pub type InternalSubsts<'tcx> = List<GenericArg<'tcx>>;
 
 pub type SubstsRef<'tcx> = &'tcx InternalSubsts<'tcx>;
@@ -266,6 +274,44 @@ impl<'a, 'tcx> InternalSubsts<'tcx> {
         self.is_empty()
     }
 
+    /// Checks, when debug assertions are enabled, that these substitutions
+    /// provide exactly one value of the right kind for each generic parameter
+    /// of `def_id`, including the parameters inherited from its parents.
+    /// Mismatches here would otherwise only surface later, as a confusing
+    /// out-of-range or wrong-kind ICE deep inside `SubstFolder`.
+    pub fn debug_assert_matches(&self, tcx: TyCtxt<'tcx>, def_id: DefId) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let generics = tcx.generics_of(def_id);
+        if generics.count() != self.len() {
+            bug!(
+                "expected {} generic arguments for {:?}, found {}: {:?}",
+                generics.count(),
+                def_id,
+                self.len(),
+                self
+            );
+        }
+
+        for (index, arg) in self.iter().enumerate() {
+            let param = generics.param_at(index, tcx);
+            match (&param.kind, arg.unpack()) {
+                (ty::GenericParamDefKind::Lifetime, GenericArgKind::Lifetime(_))
+                | (ty::GenericParamDefKind::Type { .. }, GenericArgKind::Type(_))
+                | (ty::GenericParamDefKind::Const, GenericArgKind::Const(_)) => {}
+                (kind, _) => bug!(
+                    "generic argument #{} of {:?} does not match its parameter {:?}: {:?}",
+                    index,
+                    def_id,
+                    kind,
+                    self
+                ),
+            }
+        }
+    }
+
     #[inline]
     pub fn types(&'a self) -> impl DoubleEndedIterator<Item = Ty<'tcx>> + 'a {
         self.iter()
@@ -459,9 +505,11 @@ impl<'a, 'tcx> TypeFolder<'tcx> for SubstFolder<'a, 'tcx> {
     fn fold_region(&mut self, r: ty::Region<'tcx>) -> ty::Region<'tcx> {
         // Note: This routine only handles regions that are bound on
         // type declarations and other outer declarations, not those
-        // bound in *fn types*. Region substitution of the bound
-        // regions that appear in a function signature is done using
-        // the specialized routine `ty::replace_late_regions()`.
+        // bound in *fn types*. Those are represented as `ReLateBound`
+        // with a De Bruijn index and pass through here untouched (see
+        // `fold_binder` and `shift_region_through_binders`); they are
+        // instantiated by `TyCtxt::replace_late_bound_regions` once the
+        // enclosing binder is opened.
         match *r {
             ty::ReEarlyBound(data) => {
                 let rk = self.substs.get(data.index as usize).map(|k| k.unpack());
@@ -113,23 +113,24 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                 }
             }
 
-            let msg = ""; //FIXME: add "partially " or "collaterally "
+            let is_partial_move = move_site_vec.iter().any(|move_site| {
+                let move_out = self.move_data.moves[(*move_site).moi];
+                let moved_place = &self.move_data.move_paths[move_out.path].place;
+                self.is_partial_move(used_place, moved_place.as_ref())
+            });
+
+            let partial_str = if is_partial_move { "partial " } else { "" };
 
             let mut err = self.cannot_act_on_moved_value(
                 span,
                 desired_action.as_noun(),
-                msg,
+                if is_partial_move { "partially " } else { "" },
                 self.describe_place_with_options(moved_place, IncludingDowncast(true)),
             );
 
             self.add_moved_or_invoked_closure_note(location, used_place, &mut err);
 
             let mut is_loop_move = false;
-            let is_partial_move = move_site_vec.iter().any(|move_site| {
-                let move_out = self.move_data.moves[(*move_site).moi];
-                let moved_place = &self.move_data.move_paths[move_out.path].place;
-                used_place != moved_place.as_ref() && used_place.is_prefix_of(moved_place.as_ref())
-            });
             for move_site in &move_site_vec {
                 let move_out = self.move_data.moves[(*move_site).moi];
                 let moved_place = &self.move_data.move_paths[move_out.path].place;
@@ -138,17 +139,28 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                 let move_span = move_spans.args_or_use();
 
                 let move_msg = if move_spans.for_closure() { " into closure" } else { "" };
+                let partially_str = if self.is_partial_move(used_place, moved_place.as_ref()) {
+                    "partially "
+                } else {
+                    ""
+                };
 
                 if location == move_out.source {
                     err.span_label(
                         span,
-                        format!("value moved{} here, in previous iteration of loop", move_msg),
+                        format!(
+                            "value {}moved{} here, in previous iteration of loop",
+                            partially_str, move_msg
+                        ),
                     );
                     is_loop_move = true;
                 } else if move_site.traversed_back_edge {
                     err.span_label(
                         move_span,
-                        format!("value moved{} here, in previous iteration of loop", move_msg),
+                        format!(
+                            "value {}moved{} here, in previous iteration of loop",
+                            partially_str, move_msg
+                        ),
                     );
                 } else {
                     if let UseSpans::FnSelfUse { var_span, fn_call_span, fn_span, kind } =
@@ -207,7 +219,10 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                             }
                         }
                     } else {
-                        err.span_label(move_span, format!("value moved{} here", move_msg));
+                        err.span_label(
+                            move_span,
+                            format!("value {}moved{} here", partially_str, move_msg),
+                        );
                         move_spans.var_span_label(
                             &mut err,
                             format!("variable moved due to use{}", move_spans.describe()),
@@ -250,9 +265,9 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                 err.span_label(
                     span,
                     format!(
-                        "value {} here {}",
+                        "value {} here after {}move",
                         desired_action.as_verb_in_past_tense(),
-                        if is_partial_move { "after partial move" } else { "after move" },
+                        partial_str
                     ),
                 );
             }
@@ -321,7 +336,9 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
                 } else {
                     None
                 };
-                self.note_type_does_not_implement_copy(&mut err, &note_msg, ty, span);
+                let move_prefix =
+                    if self.is_partial_move(used_place, place.as_ref()) { "partial " } else { "" };
+                self.note_type_does_not_implement_copy(&mut err, &note_msg, ty, span, move_prefix);
             }
 
             if let Some((_, mut old_err)) =
@@ -333,6 +350,16 @@ impl<'cx, 'tcx> MirBorrowckCtxt<'cx, 'tcx> {
         }
     }
 
+    /// Returns `true` if moving out of `moved_place` leaves `used_place` only partially moved.
+    /// A move out of `*b` where `b` is a `Box` moves the whole contents of the box, so it is
+    /// not partial.
+    fn is_partial_move(&self, used_place: PlaceRef<'tcx>, moved_place: PlaceRef<'tcx>) -> bool {
+        let is_box_move = moved_place.projection == &[ProjectionElem::Deref]
+            && self.body.local_decls[moved_place.local].ty.is_box();
+
+        !is_box_move && used_place != moved_place && used_place.is_prefix_of(moved_place)
+    }
+
     pub(in crate::borrow_check) fn report_move_out_while_borrowed(
         &mut self,
         location: Location,
@@ -27,6 +27,7 @@ use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::mem;
 use std::rc::Rc;
+use std::time::Instant;
 
 use crate::dataflow;
 use crate::dataflow::impls::{
@@ -128,6 +129,7 @@ fn do_mir_borrowck<'a, 'tcx>(
     debug!("do_mir_borrowck(def = {:?})", def);
 
     let tcx = infcx.tcx;
+    let stats_start = tcx.sess.borrowck_stats().then(Instant::now);
     let param_env = tcx.param_env(def.did);
     let id = tcx.hir().as_local_hir_id(def.did);
 
@@ -203,10 +205,11 @@ fn do_mir_borrowck<'a, 'tcx>(
 
     let mdpe = MoveDataParamEnv { move_data, param_env };
 
-    let mut flow_inits = MaybeInitializedPlaces::new(tcx, &body, &mdpe)
+    let flow_inits = MaybeInitializedPlaces::new(tcx, &body, &mdpe)
         .into_engine(tcx, &body, def.did.to_def_id())
-        .iterate_to_fixpoint()
-        .into_results_cursor(&body);
+        .iterate_to_fixpoint();
+    let flow_inits_iterations = flow_inits.iterations();
+    let mut flow_inits = flow_inits.into_results_cursor(&body);
 
     let locals_are_invalidated_at_exit = tcx.hir().body_owner_kind(id).is_fn_or_closure();
     let borrow_set =
@@ -271,6 +274,10 @@ fn do_mir_borrowck<'a, 'tcx>(
     let flow_ever_inits = EverInitializedPlaces::new(tcx, &body, &mdpe)
         .into_engine(tcx, &body, def.did.to_def_id())
         .iterate_to_fixpoint();
+    let dataflow_iterations = flow_inits_iterations
+        + flow_borrows.iterations()
+        + flow_uninits.iterations()
+        + flow_ever_inits.iterations();
 
     let movable_generator = match tcx.hir().get(id) {
         Node::Expr(&hir::Expr {
@@ -460,6 +467,20 @@ fn do_mir_borrowck<'a, 'tcx>(
         }
     }
 
+    if let Some(start) = stats_start {
+        println!(
+            "borrowck stats for `{}`: {} basic blocks, {} loans, {} move paths, {} moves, \
+             {} dataflow iterations, {:.3}ms",
+            tcx.def_path_str(def.did.to_def_id()),
+            body.basic_blocks().len(),
+            mbcx.borrow_set.borrows.len(),
+            mdpe.move_data.move_paths.len(),
+            mdpe.move_data.moves.len(),
+            dataflow_iterations,
+            start.elapsed().as_secs_f64() * 1000.0,
+        );
+    }
+
     let result = BorrowCheckResult {
         concrete_opaque_types: opaque_type_values,
         closure_requirements: opt_closure_req,
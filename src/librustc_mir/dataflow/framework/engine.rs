@@ -27,6 +27,7 @@ where
 {
     pub analysis: A,
     pub(super) entry_sets: IndexVec<BasicBlock, BitSet<A::Idx>>,
+    pub(super) iterations: usize,
 }
 
 impl<A> Results<'tcx, A>
@@ -43,6 +44,12 @@ where
         &self.entry_sets[block]
     }
 
+    /// Returns the number of times a block was taken off the worklist while computing these
+    /// `Results`.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
     pub fn visit_with(
         &self,
         body: &'mir mir::Body<'tcx>,
@@ -198,6 +205,9 @@ where
             ..
         } = self;
 
+        let _prof_timer =
+            tcx.prof.generic_activity_with_arg("dataflow_iterate_to_fixpoint", A::NAME);
+
         let mut dirty_queue: WorkQueue<BasicBlock> =
             WorkQueue::with_none(body.basic_blocks().len());
 
@@ -214,7 +224,9 @@ where
         }
 
         let mut state = BitSet::new_empty(bits_per_block);
+        let mut iterations = 0;
         while let Some(bb) = dirty_queue.pop() {
+            iterations += 1;
             let bb_data = &body[bb];
 
             // Apply the block transfer function, using the cached one if it exists.
@@ -240,7 +252,7 @@ where
             );
         }
 
-        let results = Results { analysis, entry_sets };
+        let results = Results { analysis, entry_sets, iterations };
 
         let res = write_graphviz_results(tcx, def_id, &body, &results, trans_for_block);
         if let Err(e) = res {
@@ -270,8 +270,8 @@ impl SeekTarget {
 fn test_cursor<D: Direction>(analysis: MockAnalysis<'tcx, D>) {
     let body = analysis.body;
 
-    let mut cursor =
-        Results { entry_sets: analysis.mock_entry_sets(), analysis }.into_results_cursor(body);
+    let mut cursor = Results { entry_sets: analysis.mock_entry_sets(), analysis, iterations: 0 }
+        .into_results_cursor(body);
 
     let every_target = || {
         body.basic_blocks()
@@ -992,6 +992,19 @@ fn collect_and_partition_mono_items(
         }
     }
 
+    if tcx.sess.opts.debugging_opts.print_symbol_names {
+        let mut symbols: Vec<_> = items
+            .iter()
+            .map(|item| format!("{} {}", item.symbol_name(tcx), item.to_string(tcx, false)))
+            .collect();
+
+        symbols.sort();
+
+        for symbol in symbols {
+            println!("SYMBOL_NAME {}", symbol);
+        }
+    }
+
     (tcx.arena.alloc(mono_items), codegen_units)
 }
 
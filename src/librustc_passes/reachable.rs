@@ -5,6 +5,7 @@
 // makes all other generics or inline functions that it references
 // reachable as well.
 
+use rustc_attr::InlineAttr;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
@@ -21,10 +22,12 @@ use rustc_session::config::CrateType;
 use rustc_target::spec::abi::Abi;
 
 // Returns true if the given item must be inlined because it may be
-// monomorphized or it was marked with `#[inline]`. This will only return
-// true for functions.
+// monomorphized or it was marked with `#[inline]`, explicitly or through
+// `-Z cross-crate-inline-threshold`. This will only return true for functions.
 fn item_might_be_inlined(tcx: TyCtxt<'tcx>, item: &hir::Item<'_>, attrs: &CodegenFnAttrs) -> bool {
-    if attrs.requests_inline() {
+    if attrs.requests_inline()
+        || tcx.cross_crate_inlinable(tcx.hir().local_def_id(item.hir_id).to_def_id())
+    {
         return true;
     }
 
@@ -44,8 +47,12 @@ fn method_might_be_inlined(
     impl_src: LocalDefId,
 ) -> bool {
     let codegen_fn_attrs = tcx.codegen_fn_attrs(impl_item.hir_id.owner.to_def_id());
-    let generics = tcx.generics_of(tcx.hir().local_def_id(impl_item.hir_id));
-    if codegen_fn_attrs.requests_inline() || generics.requires_monomorphization(tcx) {
+    let def_id = tcx.hir().local_def_id(impl_item.hir_id).to_def_id();
+    let generics = tcx.generics_of(def_id);
+    if codegen_fn_attrs.requests_inline()
+        || tcx.cross_crate_inlinable(def_id)
+        || generics.requires_monomorphization(tcx)
+    {
         return true;
     }
     if let hir::ImplItemKind::Fn(method_sig, _) = &impl_item.kind {
@@ -174,7 +181,10 @@ impl<'tcx> ReachableContext<'tcx> {
                     hir::ImplItemKind::Fn(..) => {
                         let attrs = self.tcx.codegen_fn_attrs(def_id);
                         let generics = self.tcx.generics_of(def_id);
-                        if generics.requires_monomorphization(self.tcx) || attrs.requests_inline() {
+                        if generics.requires_monomorphization(self.tcx)
+                            || attrs.requests_inline()
+                            || self.tcx.cross_crate_inlinable(def_id)
+                        {
                             true
                         } else {
                             let impl_did = self.tcx.hir().get_parent_did(hir_id);
@@ -429,6 +439,58 @@ fn reachable_set<'tcx>(tcx: TyCtxt<'tcx>, crate_num: CrateNum) -> &'tcx HirIdSet
     tcx.arena.alloc(reachable_context.reachable_symbols)
 }
 
+/// Returns `true` if `-Z cross-crate-inline-threshold` treats the local function `def_id` as if
+/// it were marked `#[inline]`. That is the case for functions that other crates can reach, that
+/// have no attribute saying otherwise, and whose body has at most `threshold` HIR expressions.
+///
+/// This has to stay out of `codegen_fn_attrs`: the privacy pass can end up borrow-checking
+/// functions that return `impl Trait`, and building their MIR asks for `codegen_fn_attrs`.
+fn cross_crate_inlinable(tcx: TyCtxt<'_>, def_id: DefId) -> bool {
+    struct ExprCounter(usize);
+
+    impl<'v> Visitor<'v> for ExprCounter {
+        type Map = intravisit::ErasedMap<'v>;
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+        fn visit_expr(&mut self, expr: &'v hir::Expr<'v>) {
+            self.0 += 1;
+            intravisit::walk_expr(self, expr)
+        }
+    }
+
+    let threshold = match tcx.sess.opts.debugging_opts.cross_crate_inline_threshold {
+        Some(threshold) => threshold,
+        None => return false,
+    };
+    if !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+        return false;
+    }
+
+    let codegen_fn_attrs = tcx.codegen_fn_attrs(def_id);
+    if codegen_fn_attrs.inline != InlineAttr::None
+        || !codegen_fn_attrs.target_features.is_empty()
+        || codegen_fn_attrs.contains_extern_indicator()
+        || codegen_fn_attrs.flags.intersects(CodegenFnAttrFlags::COLD | CodegenFnAttrFlags::NAKED)
+    {
+        return false;
+    }
+
+    let hir_id = tcx.hir().as_local_hir_id(def_id.expect_local());
+    if !tcx.privacy_access_levels(LOCAL_CRATE).is_exported(hir_id) {
+        return false;
+    }
+    let body_id = match tcx.hir().maybe_body_owned_by(hir_id) {
+        Some(body_id) => body_id,
+        None => return false,
+    };
+
+    let mut counter = ExprCounter(0);
+    counter.visit_body(tcx.hir().body(body_id));
+    counter.0 <= threshold
+}
+
 pub fn provide(providers: &mut Providers) {
-    *providers = Providers { reachable_set, ..*providers };
+    *providers = Providers { reachable_set, cross_crate_inlinable, ..*providers };
 }
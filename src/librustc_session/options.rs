@@ -806,13 +806,16 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
     borrowck: String = ("migrate".to_string(), parse_string, [UNTRACKED],
         "select which borrowck is used (`mir` or `migrate`) (default: `migrate`)"),
     borrowck_stats: bool = (false, parse_bool, [UNTRACKED],
-        "gather borrowck statistics (default: no)"),
+        "print per-function borrowck statistics (default: no)"),
     chalk: bool = (false, parse_bool, [TRACKED],
         "enable the experimental Chalk-based trait solving engine"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
         "the backend to use"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
         "inject the given attribute in the crate"),
+    cross_crate_inline_threshold: Option<usize> = (None, parse_opt_uint, [TRACKED],
+        "treat exported functions whose bodies have at most this many expressions as `#[inline]`, \
+        making them available for inlining in other crates (default: off)"),
     debug_macros: bool = (false, parse_bool, [TRACKED],
         "emit line numbers debug info inside macros (default: no)"),
     deduplicate_diagnostics: bool = (true, parse_bool, [UNTRACKED],
@@ -849,6 +852,10 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "in addition to `.mir` files, create graphviz `.dot` files (default: no)"),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
         "emit a section containing stack size metadata (default: no)"),
+    fast_math: bool = (false, parse_bool, [TRACKED],
+        "allow LLVM to reassociate and otherwise loosen IEEE semantics of floating-point \
+        arithmetic; any operation that takes or produces a NaN or infinity is then \
+        undefined behavior (default: no)"),
     fewer_names: bool = (false, parse_bool, [TRACKED],
         "reduce memory use by retaining fewer names within compilation artifacts (LLVM-IR) \
         (default: no)"),
@@ -959,10 +966,15 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "make rustc print the total optimization fuel used by a crate"),
     print_link_args: bool = (false, parse_bool, [UNTRACKED],
         "print the arguments passed to the linker (default: no)"),
+    print_llvm_ir_for: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "print the LLVM IR of every function whose demangled name contains this string, \
+        before and after optimization (default: no)"),
     print_llvm_passes: bool = (false, parse_bool, [UNTRACKED],
         "print the LLVM optimization passes being run (default: no)"),
     print_mono_items: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "print the result of the monomorphization collection pass"),
+    print_symbol_names: bool = (false, parse_bool, [UNTRACKED],
+        "print the mangled symbol name of every monomorphized item (default: no)"),
     print_type_sizes: bool = (false, parse_bool, [UNTRACKED],
         "print layout information for each type encountered (default: no)"),
     profile: bool = (false, parse_bool, [TRACKED],
@@ -5726,6 +5726,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                 },
             )
         });
+        substs.debug_assert_matches(tcx, def_id);
         assert!(!substs.has_escaping_bound_vars());
         assert!(!ty.has_escaping_bound_vars());
 
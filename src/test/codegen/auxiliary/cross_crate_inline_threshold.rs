@@ -0,0 +1,24 @@
+// compile-flags: -C opt-level=3 -Z cross-crate-inline-threshold=5
+
+#![crate_type = "lib"]
+
+pub fn small(x: u32) -> u32 {
+    x + 1
+}
+
+pub fn large(x: u32) -> u32 {
+    let mut acc = x;
+    for i in 0..x {
+        acc = acc.wrapping_mul(31).wrapping_add(i);
+        if acc % 7 == 0 {
+            acc ^= i << 3;
+        }
+    }
+    acc
+}
+
+// Privacy checking looks at the hidden type of `impl Trait`, which borrow-checks this function
+// and so asks for its codegen attributes. That must not depend on the inlining decision.
+pub fn counter() -> impl Iterator<Item = u32> {
+    0..3
+}
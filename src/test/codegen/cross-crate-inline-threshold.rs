@@ -0,0 +1,23 @@
+// Checks that `-Z cross-crate-inline-threshold` makes small upstream functions
+// inlinable downstream while larger ones are still called.
+// aux-build:cross_crate_inline_threshold.rs
+// compile-flags: -C opt-level=3
+
+#![crate_type = "lib"]
+
+extern crate cross_crate_inline_threshold;
+
+// CHECK-LABEL: @calls_small
+// CHECK-NOT: call
+// CHECK: ret i32
+#[no_mangle]
+pub fn calls_small(x: u32) -> u32 {
+    cross_crate_inline_threshold::small(x)
+}
+
+// CHECK-LABEL: @calls_large
+// CHECK: call {{.*}}large
+#[no_mangle]
+pub fn calls_large(x: u32) -> u32 {
+    cross_crate_inline_threshold::large(x)
+}
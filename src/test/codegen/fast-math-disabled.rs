@@ -0,0 +1,44 @@
+// Checks that primitive float arithmetic has no `fast` flag without `-Z fast-math`.
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+// CHECK-LABEL: @addition
+#[no_mangle]
+pub fn addition(x: f32, y: f32) -> f32 {
+// CHECK: fadd float
+// CHECK-NOT: fast
+    x + y
+}
+
+// CHECK-LABEL: @subtraction
+#[no_mangle]
+pub fn subtraction(x: f32, y: f32) -> f32 {
+// CHECK: fsub float
+// CHECK-NOT: fast
+    x - y
+}
+
+// CHECK-LABEL: @multiplication
+#[no_mangle]
+pub fn multiplication(x: f32, y: f32) -> f32 {
+// CHECK: fmul float
+// CHECK-NOT: fast
+    x * y
+}
+
+// CHECK-LABEL: @division
+#[no_mangle]
+pub fn division(x: f32, y: f32) -> f32 {
+// CHECK: fdiv float
+// CHECK-NOT: fast
+    x / y
+}
+
+// CHECK-LABEL: @remainder
+#[no_mangle]
+pub fn remainder(x: f32, y: f32) -> f32 {
+// CHECK: frem float
+// CHECK-NOT: fast
+    x % y
+}
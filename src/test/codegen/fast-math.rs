@@ -0,0 +1,39 @@
+// Checks that `-Z fast-math` puts the `fast` flag on primitive float arithmetic.
+// compile-flags: -C no-prepopulate-passes -Z fast-math
+
+#![crate_type = "lib"]
+
+// CHECK-LABEL: @addition
+#[no_mangle]
+pub fn addition(x: f32, y: f32) -> f32 {
+// CHECK: fadd fast float
+    x + y
+}
+
+// CHECK-LABEL: @subtraction
+#[no_mangle]
+pub fn subtraction(x: f32, y: f32) -> f32 {
+// CHECK: fsub fast float
+    x - y
+}
+
+// CHECK-LABEL: @multiplication
+#[no_mangle]
+pub fn multiplication(x: f32, y: f32) -> f32 {
+// CHECK: fmul fast float
+    x * y
+}
+
+// CHECK-LABEL: @division
+#[no_mangle]
+pub fn division(x: f32, y: f32) -> f32 {
+// CHECK: fdiv fast float
+    x / y
+}
+
+// CHECK-LABEL: @remainder
+#[no_mangle]
+pub fn remainder(x: f32, y: f32) -> f32 {
+// CHECK: frem fast float
+    x % y
+}
@@ -0,0 +1,9 @@
+#![crate_type = "lib"]
+
+pub fn wanted_function(x: u32) -> u32 {
+    x.wrapping_mul(3)
+}
+
+pub fn unrelated_function(x: u32) -> u32 {
+    x.wrapping_add(7)
+}
@@ -0,0 +1,7 @@
+#![crate_type = "lib"]
+
+pub static ANSWER: u32 = 42;
+
+pub fn exported_function(x: u32) -> u32 {
+    x.wrapping_add(ANSWER)
+}
@@ -0,0 +1,6 @@
+// Checks the line printed for each function by `-Z borrowck-stats`.
+// compile-flags: -Z borrowck-stats
+// check-pass
+// normalize-stdout-test "\d+\.\d+ms" -> "TIMEms"
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Checks that each move site is labelled as a partial or a full move on its own.
+
+fn main() {
+    let c = true;
+    let x = (String::new(), String::new());
+    if c {
+        drop(x.0);
+    } else {
+        drop(x);
+    }
+    drop(x); //~ ERROR use of partially moved value: `x`
+}